@@ -13,7 +13,9 @@
 
 use ast_map;
 use session::Session;
-use middle::def::{DefStatic, DefConst, DefAssociatedConst, DefMap};
+use middle::def::{DefStatic, DefConst, DefAssociatedConst, DefVariant, DefMap};
+
+use std::mem;
 
 use syntax::{ast, ast_util};
 use syntax::codemap::Span;
@@ -36,6 +38,18 @@ impl<'v, 'a, 'ast> Visitor<'v> for CheckCrateVisitor<'a, 'ast> {
                 recursion_visitor.visit_item(it);
                 visit::walk_expr(self, &*expr)
             },
+            ast::ItemEnum(ref enum_definition, _) => {
+                // Discriminants are constant expressions too, so each variant's
+                // discriminant gets its own recursion check.
+                for variant in &enum_definition.variants {
+                    if let Some(ref expr) = variant.node.disr_expr {
+                        let mut recursion_visitor =
+                            CheckItemRecursionVisitor::new(self, &variant.span);
+                        recursion_visitor.check_variant(&**variant);
+                        visit::walk_expr(self, &*expr)
+                    }
+                }
+            },
             _ => visit::walk_item(self, it)
         }
     }
@@ -85,7 +99,14 @@ struct CheckItemRecursionVisitor<'a, 'ast: 'a> {
     sess: &'a Session,
     ast_map: &'a ast_map::Map<'ast>,
     def_map: &'a DefMap,
-    idstack: Vec<ast::NodeId>
+    // Each entry pairs an item on the recursion chain with the span of the
+    // *reference* that led us to it, so that when we close a cycle we can
+    // point the user at every offending expression rather than at a single
+    // item definition.
+    idstack: Vec<(ast::NodeId, Span)>,
+    // Span of the reference currently being followed: the use span, not the
+    // definition span, of whatever item we are about to descend into.
+    reference_span: Span
 }
 
 impl<'a, 'ast: 'a> CheckItemRecursionVisitor<'a, 'ast> {
@@ -96,19 +117,55 @@ impl<'a, 'ast: 'a> CheckItemRecursionVisitor<'a, 'ast> {
             sess: v.sess,
             ast_map: v.ast_map,
             def_map: v.def_map,
-            idstack: Vec::new()
+            idstack: Vec::new(),
+            reference_span: *span
         }
     }
     fn with_item_id_pushed<F>(&mut self, id: ast::NodeId, f: F)
           where F: Fn(&mut Self) {
-        if self.idstack.iter().any(|x| x == &(id)) {
+        if let Some(index) = self.idstack.iter().position(|&(x, _)| x == id) {
             span_err!(self.sess, *self.root_span, E0265, "recursive constant");
+            // Walk the chain from the repeated item forward and back around to
+            // itself, noting each reference that makes up the cycle.
+            let cycle: Vec<(ast::NodeId, Span)> =
+                self.idstack[index..].iter().cloned().collect();
+            for (i, &(cur_id, _)) in cycle.iter().enumerate() {
+                let (next_id, here) = if i + 1 < cycle.len() {
+                    (cycle[i + 1].0, cycle[i + 1].1)
+                } else {
+                    (id, self.reference_span)
+                };
+                self.sess.span_note(
+                    here,
+                    &format!("`{}` references `{}` here",
+                             self.name_of(cur_id), self.name_of(next_id)));
+            }
             return;
         }
-        self.idstack.push(id);
+        self.idstack.push((id, self.reference_span));
         f(self);
         self.idstack.pop();
     }
+    // The human-readable name of an item, trait/impl const or enum variant on
+    // the recursion chain, used when describing a cycle.
+    fn name_of(&self, id: ast::NodeId) -> String {
+        match self.ast_map.get(id) {
+            ast_map::NodeItem(item) => item.ident.to_string(),
+            ast_map::NodeTraitItem(item) => item.ident.to_string(),
+            ast_map::NodeImplItem(item) => item.ident.to_string(),
+            ast_map::NodeVariant(variant) => variant.node.name.to_string(),
+            _ => self.ast_map.node_to_string(id)
+        }
+    }
+    // Descend into an enum variant's discriminant, treating the variant as the
+    // item on the recursion chain.
+    fn check_variant(&mut self, variant: &ast::Variant) {
+        self.with_item_id_pushed(variant.node.id, |v| {
+            if let Some(ref expr) = variant.node.disr_expr {
+                v.visit_expr(&**expr);
+            }
+        });
+    }
 }
 
 impl<'a, 'ast, 'v> Visitor<'v> for CheckItemRecursionVisitor<'a, 'ast> {
@@ -132,6 +189,7 @@ impl<'a, 'ast, 'v> Visitor<'v> for CheckItemRecursionVisitor<'a, 'ast> {
                     Some(DefAssociatedConst(def_id, _)) |
                     Some(DefConst(def_id)) if
                             ast_util::is_local(def_id) => {
+                        let old = mem::replace(&mut self.reference_span, e.span);
                         match self.ast_map.get(def_id.node) {
                           ast_map::NodeItem(item) =>
                             self.visit_item(item),
@@ -144,9 +202,21 @@ impl<'a, 'ast, 'v> Visitor<'v> for CheckItemRecursionVisitor<'a, 'ast> {
                             span_err!(self.sess, e.span, E0266,
                               "expected item, found {}",
                                       self.ast_map.node_to_string(def_id.node));
+                            self.reference_span = old;
                             return;
                           },
                         }
+                        self.reference_span = old;
+                    }
+                    Some(DefVariant(_, variant_id, _)) if
+                            ast_util::is_local(variant_id) => {
+                        if let ast_map::NodeVariant(variant) =
+                                self.ast_map.get(variant_id.node) {
+                            let old = mem::replace(&mut self.reference_span,
+                                                   e.span);
+                            self.check_variant(&*variant);
+                            self.reference_span = old;
+                        }
                     }
                     _ => ()
                 }
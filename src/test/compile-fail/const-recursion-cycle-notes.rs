@@ -0,0 +1,21 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that a recursive constant spanning several items reports every edge
+// of the cycle, not just a bare "recursive constant".
+
+const A: i32 = B; //~ ERROR recursive constant
+                  //~| NOTE `A` references `B` here
+const B: i32 = C; //~ ERROR recursive constant
+                  //~| NOTE `B` references `C` here
+const C: i32 = A; //~ ERROR recursive constant
+                  //~| NOTE `C` references `A` here
+
+fn main() {}
@@ -0,0 +1,19 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that a cycle laundered through enum discriminants is caught by the
+// recursion checker instead of blowing up later in const eval.
+
+enum E { A = F::B as isize } //~ ERROR recursive constant
+                             //~| NOTE `A` references `B` here
+enum F { B = E::A as isize } //~ ERROR recursive constant
+                             //~| NOTE `B` references `A` here
+
+fn main() {}
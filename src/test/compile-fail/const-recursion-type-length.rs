@@ -0,0 +1,20 @@
+// Copyright 2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that a cycle laundered through a type — here a constant used as the
+// array length in another constant's own type — is caught by the recursion
+// checker rather than surfacing later as a const-eval or layout failure.
+
+const A: [u8; B] = [0u8; 0]; //~ ERROR recursive constant
+                             //~| NOTE `A` references `B` here
+const B: usize = A[0] as usize; //~ ERROR recursive constant
+                                //~| NOTE `B` references `A` here
+
+fn main() {}